@@ -1,19 +1,59 @@
 // Prevents additional console window on Windows in release
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+mod settings;
 mod wake_word;
 
 use std::sync::atomic::{AtomicBool, Ordering};
 use tauri::{
-    AppHandle, Manager,
+    AppHandle, Manager, Wry,
     menu::{Menu, MenuItem},
     tray::{TrayIconBuilder, TrayIconEvent, MouseButton, MouseButtonState},
 };
-// use tauri_plugin_autostart::MacosLauncher;  // Disabled for now
+use tauri_plugin_autostart::{MacosLauncher, ManagerExt};
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
+use tauri_plugin_notification::NotificationExt;
+use tauri_plugin_updater::UpdaterExt;
 
 // Global state for wake word detection
 static WAKE_WORD_ENABLED: AtomicBool = AtomicBool::new(false);
 
+/// Tray `MenuItem` handles kept around so their labels can be updated to
+/// reflect live state instead of staying fixed at the text they were
+/// created with.
+struct TrayMenuItems {
+    show_item: MenuItem<Wry>,
+    wake_word_item: MenuItem<Wry>,
+}
+
+/// Flip the wake word menu item between "Enable Wake Word" / "Disable Wake
+/// Word" to match `WAKE_WORD_ENABLED`.
+fn sync_wake_word_label(app: &AppHandle) {
+    let Some(items) = app.try_state::<TrayMenuItems>() else {
+        return;
+    };
+    let label = if WAKE_WORD_ENABLED.load(Ordering::SeqCst) {
+        "Disable Wake Word"
+    } else {
+        "Enable Wake Word"
+    };
+    let _ = items.wake_word_item.set_text(label);
+}
+
+/// Flip the show/hide menu item between "Show Chief" / "Hide Chief" to
+/// match the main window's current visibility.
+fn sync_show_label(app: &AppHandle) {
+    let Some(items) = app.try_state::<TrayMenuItems>() else {
+        return;
+    };
+    let visible = app
+        .get_webview_window("main")
+        .map(|window| window.is_visible().unwrap_or(false))
+        .unwrap_or(false);
+    let label = if visible { "Hide Chief" } else { "Show Chief" };
+    let _ = items.show_item.set_text(label);
+}
+
 #[tauri::command]
 fn enable_wake_word(app: AppHandle) -> Result<String, String> {
     if WAKE_WORD_ENABLED.load(Ordering::SeqCst) {
@@ -21,6 +61,7 @@ fn enable_wake_word(app: AppHandle) -> Result<String, String> {
     }
 
     WAKE_WORD_ENABLED.store(true, Ordering::SeqCst);
+    sync_wake_word_label(&app);
 
     // Start wake word detection in background thread
     let app_handle = app.clone();
@@ -34,8 +75,9 @@ fn enable_wake_word(app: AppHandle) -> Result<String, String> {
 }
 
 #[tauri::command]
-fn disable_wake_word() -> Result<String, String> {
+fn disable_wake_word(app: AppHandle) -> Result<String, String> {
     WAKE_WORD_ENABLED.store(false, Ordering::SeqCst);
+    sync_wake_word_label(&app);
     Ok("Wake word detection disabled".to_string())
 }
 
@@ -44,12 +86,120 @@ fn is_wake_word_enabled() -> bool {
     WAKE_WORD_ENABLED.load(Ordering::SeqCst)
 }
 
+/// Show-and-focus the main window, or hide it if it's already visible.
+/// Shared by the tray icon click and the global hotkey so they behave
+/// identically.
+fn toggle_main_window(app: &AppHandle) {
+    if let Some(window) = app.get_webview_window("main") {
+        if window.is_visible().unwrap_or(false) {
+            let _ = window.hide();
+        } else {
+            let _ = window.show();
+            let _ = window.set_focus();
+        }
+    }
+    sync_show_label(app);
+}
+
+#[tauri::command]
+fn set_global_hotkey(app: AppHandle, accelerator: String) -> Result<(), String> {
+    let shortcut: Shortcut = accelerator
+        .parse()
+        .map_err(|e| format!("invalid accelerator '{accelerator}': {e}"))?;
+
+    let gs = app.global_shortcut();
+    gs.unregister_all().map_err(|e| e.to_string())?;
+    gs.register(shortcut).map_err(|e| e.to_string())?;
+
+    settings::save_global_hotkey(&app, &accelerator)?;
+    log::info!("Global hotkey set to {accelerator}");
+
+    Ok(())
+}
+
+#[tauri::command]
+fn get_global_hotkey(app: AppHandle) -> String {
+    settings::load_global_hotkey(&app)
+}
+
+/// The most recently found update, cached so `install_update` can act on the
+/// exact release `check_for_update`/the startup check already found instead
+/// of hitting the release channel a second time.
+struct PendingUpdate(std::sync::Mutex<Option<tauri_plugin_updater::Update>>);
+
+/// Check the release channel for a newer version and, if one exists, cache
+/// it for `install_update` and surface a native notification. Used both for
+/// the silent startup check and the "Check for Updates…" tray item.
+async fn check_for_update_and_notify(app: &AppHandle) -> Result<bool, String> {
+    let Some(update) = app.updater().map_err(|e| e.to_string())?.check().await.map_err(|e| e.to_string())? else {
+        return Ok(false);
+    };
+
+    log::info!("Chief update available: {}", update.version);
+    let _ = app
+        .notification()
+        .builder()
+        .title("Chief update available")
+        .body(format!(
+            "Version {} is ready to install.",
+            update.version
+        ))
+        .show();
+
+    if let Some(pending) = app.try_state::<PendingUpdate>() {
+        *pending.0.lock().unwrap() = Some(update);
+    }
+
+    Ok(true)
+}
+
+#[tauri::command]
+async fn check_for_update(app: AppHandle) -> Result<bool, String> {
+    check_for_update_and_notify(&app).await
+}
+
+#[tauri::command]
+async fn install_update(app: AppHandle) -> Result<(), String> {
+    let pending = app
+        .try_state::<PendingUpdate>()
+        .ok_or("Updater not initialized")?;
+    let update = pending
+        .0
+        .lock()
+        .unwrap()
+        .take()
+        .ok_or("No update available - call check_for_update first")?;
+
+    update
+        .download_and_install(|_chunk, _total| {}, || {})
+        .await
+        .map_err(|e| e.to_string())?;
+
+    app.restart();
+}
+
+#[tauri::command]
+fn set_launch_at_login(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let manager = app.autolaunch();
+    if enabled {
+        manager.enable().map_err(|e| e.to_string())
+    } else {
+        manager.disable().map_err(|e| e.to_string())
+    }
+}
+
+#[tauri::command]
+fn is_launch_at_login(app: AppHandle) -> bool {
+    app.autolaunch().is_enabled().unwrap_or(false)
+}
+
 #[tauri::command]
 fn show_window(app: AppHandle) {
     if let Some(window) = app.get_webview_window("main") {
         let _ = window.show();
         let _ = window.set_focus();
     }
+    sync_show_label(&app);
 }
 
 #[tauri::command]
@@ -57,6 +207,7 @@ fn hide_window(app: AppHandle) {
     if let Some(window) = app.get_webview_window("main") {
         let _ = window.hide();
     }
+    sync_show_label(&app);
 }
 
 pub fn is_detection_enabled() -> bool {
@@ -67,21 +218,85 @@ fn main() {
     env_logger::init();
 
     tauri::Builder::default()
+        .plugin(tauri_plugin_single_instance::init(|app, _args, _cwd| {
+            // A second launch should just reveal the already-running
+            // instance instead of spawning a competing tray icon and wake
+            // word thread.
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.show();
+                let _ = window.set_focus();
+            }
+        }))
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_notification::init())
-        // Autostart disabled for now - can be added later
-        // .plugin(tauri_plugin_autostart::init(
-        //     MacosLauncher::LaunchAgent,
-        //     Some(vec!["--hidden"]),
-        // ))
+        .plugin(tauri_plugin_updater::Builder::new().build())
+        .plugin(tauri_plugin_window_state::Builder::default().build())
+        .plugin(tauri_plugin_autostart::init(
+            MacosLauncher::LaunchAgent,
+            Some(vec!["--hidden"]),
+        ))
+        .plugin(
+            tauri_plugin_global_shortcut::Builder::new()
+                .with_handler(|app, _shortcut, event| {
+                    if event.state() == ShortcutState::Pressed {
+                        toggle_main_window(app);
+                    }
+                })
+                .build(),
+        )
         .setup(|app| {
             // Create tray menu
             let show_item = MenuItem::with_id(app, "show", "Show Chief", true, None::<&str>)?;
             let wake_word_item = MenuItem::with_id(app, "wake_word", "Enable Wake Word", true, None::<&str>)?;
             let separator = MenuItem::with_id(app, "sep", "---", false, None::<&str>)?;
+            let update_item = MenuItem::with_id(app, "check_for_updates", "Check for Updates…", true, None::<&str>)?;
+            let separator2 = MenuItem::with_id(app, "sep2", "---", false, None::<&str>)?;
             let quit_item = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
 
-            let menu = Menu::with_items(app, &[&show_item, &wake_word_item, &separator, &quit_item])?;
+            let menu = Menu::with_items(
+                app,
+                &[
+                    &show_item,
+                    &wake_word_item,
+                    &separator,
+                    &update_item,
+                    &separator2,
+                    &quit_item,
+                ],
+            )?;
+
+            app.manage(TrayMenuItems {
+                show_item: show_item.clone(),
+                wake_word_item: wake_word_item.clone(),
+            });
+            app.manage(PendingUpdate(std::sync::Mutex::new(None)));
+
+            // Keep the show/hide label in sync with visibility changes that
+            // don't go through `toggle_main_window`/`show_window`/`hide_window`
+            // (e.g. the window losing visibility via the OS close button).
+            if let Some(window) = app.get_webview_window("main") {
+                let window_handle = app.handle().clone();
+                window.on_window_event(move |event| {
+                    if matches!(
+                        event,
+                        tauri::WindowEvent::CloseRequested { .. } | tauri::WindowEvent::Focused(_)
+                    ) {
+                        sync_show_label(&window_handle);
+                    }
+                });
+            }
+
+            // Register the persisted (or default) global hotkey so Chief can
+            // be summoned without hunting for the menu bar icon.
+            let hotkey = settings::load_global_hotkey(app.handle());
+            match hotkey.parse::<Shortcut>() {
+                Ok(shortcut) => {
+                    if let Err(e) = app.global_shortcut().register(shortcut) {
+                        log::error!("Failed to register global hotkey '{hotkey}': {e}");
+                    }
+                }
+                Err(e) => log::error!("Invalid saved global hotkey '{hotkey}': {e}"),
+            }
 
             // Create tray icon
             let _tray = TrayIconBuilder::new()
@@ -90,24 +305,13 @@ fn main() {
                 .show_menu_on_left_click(false)
                 .on_tray_icon_event(|tray, event| {
                     if let TrayIconEvent::Click { button: MouseButton::Left, button_state: MouseButtonState::Up, .. } = event {
-                        let app = tray.app_handle();
-                        if let Some(window) = app.get_webview_window("main") {
-                            if window.is_visible().unwrap_or(false) {
-                                let _ = window.hide();
-                            } else {
-                                let _ = window.show();
-                                let _ = window.set_focus();
-                            }
-                        }
+                        toggle_main_window(tray.app_handle());
                     }
                 })
                 .on_menu_event(|app, event| {
                     match event.id.as_ref() {
                         "show" => {
-                            if let Some(window) = app.get_webview_window("main") {
-                                let _ = window.show();
-                                let _ = window.set_focus();
-                            }
+                            toggle_main_window(app);
                         }
                         "wake_word" => {
                             if WAKE_WORD_ENABLED.load(Ordering::SeqCst) {
@@ -123,15 +327,37 @@ fn main() {
                                 });
                                 log::info!("Wake word detection enabled");
                             }
+                            sync_wake_word_label(app);
+                        }
+                        "check_for_updates" => {
+                            let app_handle = app.clone();
+                            tauri::async_runtime::spawn(async move {
+                                if let Err(e) = check_for_update_and_notify(&app_handle).await {
+                                    log::error!("Update check failed: {e}");
+                                }
+                            });
                         }
                         "quit" => {
-                            std::process::exit(0);
+                            // Exit through Tauri rather than std::process::exit
+                            // so the exit/destroy lifecycle runs and
+                            // tauri-plugin-window-state gets to persist the
+                            // window's final geometry before the process dies.
+                            app.exit(0);
                         }
                         _ => {}
                     }
                 })
                 .build(app)?;
 
+            // Silently check for updates on startup; a native notification
+            // surfaces if one is found.
+            let app_handle = app.handle().clone();
+            tauri::async_runtime::spawn(async move {
+                if let Err(e) = check_for_update_and_notify(&app_handle).await {
+                    log::error!("Startup update check failed: {e}");
+                }
+            });
+
             log::info!("Chief Desktop started");
 
             Ok(())
@@ -140,6 +366,12 @@ fn main() {
             enable_wake_word,
             disable_wake_word,
             is_wake_word_enabled,
+            set_global_hotkey,
+            get_global_hotkey,
+            check_for_update,
+            install_update,
+            set_launch_at_login,
+            is_launch_at_login,
             show_window,
             hide_window,
         ])
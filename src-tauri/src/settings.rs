@@ -0,0 +1,36 @@
+use std::fs;
+use std::path::PathBuf;
+
+use tauri::{AppHandle, Manager};
+
+/// Default global hotkey used to summon/dismiss Chief before the user
+/// rebinds it.
+pub const DEFAULT_GLOBAL_HOTKEY: &str = "CommandOrControl+Shift+Space";
+
+const HOTKEY_FILE: &str = "hotkey.txt";
+
+/// Read the persisted global hotkey, falling back to the default if it has
+/// never been set (or the settings file can't be read).
+pub fn load_global_hotkey(app: &AppHandle) -> String {
+    hotkey_path(app)
+        .and_then(|path| fs::read_to_string(path).ok())
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| DEFAULT_GLOBAL_HOTKEY.to_string())
+}
+
+/// Persist the global hotkey so it survives restarts.
+pub fn save_global_hotkey(app: &AppHandle, accelerator: &str) -> Result<(), String> {
+    let path = hotkey_path(app).ok_or("could not resolve app config dir")?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    }
+    fs::write(path, accelerator).map_err(|e| e.to_string())
+}
+
+fn hotkey_path(app: &AppHandle) -> Option<PathBuf> {
+    app.path()
+        .app_config_dir()
+        .ok()
+        .map(|dir| dir.join(HOTKEY_FILE))
+}
@@ -1,31 +1,210 @@
 use tauri::{AppHandle, Emitter};
 
-/// Start wake word detection
+/// Phrase that summons Chief. Matched case-insensitively against the
+/// on-device speech recognizer's best transcription.
+const WAKE_PHRASE: &str = "hey rosie";
+
+/// Start wake word detection.
 ///
-/// TODO: Implement using macOS native Speech Recognition API
-/// For now, this is a placeholder that notifies the user to set up wake word
+/// On macOS this bridges into `SFSpeechRecognizer` for fully on-device,
+/// privacy-preserving recognition of "Hey Rosie" - no audio ever leaves the
+/// machine. On every other platform it emits `wake-word-not-available` and
+/// then parks the thread until detection is disabled, so `enable_wake_word`
+/// doesn't need to care which OS it's running on.
 pub fn start_detection(app: AppHandle) -> Result<(), Box<dyn std::error::Error>> {
-    log::info!("Wake word detection not yet implemented");
-    log::info!("Future: Will use macOS Speech Recognition API for 'Hey Rosie' detection");
+    #[cfg(target_os = "macos")]
+    {
+        macos::run(app)
+    }
 
-    // Notify frontend that wake word training/setup is needed
-    let _ = app.emit("wake-word-not-available", "Wake word detection coming soon. Use the menu bar icon to open Chief.");
+    #[cfg(not(target_os = "macos"))]
+    {
+        log::warn!("Wake word detection is only available on macOS");
+        let _ = app.emit(
+            "wake-word-not-available",
+            "Wake word detection is only available on macOS. Use the menu bar icon to open Chief.",
+        );
 
-    // For now, just keep the thread alive until disabled
-    while crate::is_detection_enabled() {
-        std::thread::sleep(std::time::Duration::from_millis(1000));
-    }
+        while crate::is_detection_enabled() {
+            std::thread::sleep(std::time::Duration::from_millis(1000));
+        }
 
-    Ok(())
+        Ok(())
+    }
 }
 
-// Future: Use macOS native Speech Recognition
-//
-// This will use the NSSpeechRecognizer or SFSpeechRecognizer APIs
-// to detect the wake word "Hey Rosie" without any external dependencies
-//
-// Benefits:
-// - No dependency conflicts
-// - Uses system-provided ML models
-// - Privacy-preserving (on-device)
-// - Low battery impact
+#[cfg(target_os = "macos")]
+mod macos {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::mpsc::channel;
+    use std::time::Duration;
+
+    use block::ConcreteBlock;
+    use cocoa::base::{id, nil};
+    use objc::rc::autoreleasepool;
+    use objc::runtime::{BOOL, YES};
+    use objc::{class, msg_send, sel, sel_impl};
+    use tauri::{AppHandle, Emitter};
+
+    use super::WAKE_PHRASE;
+
+    /// `SFSpeechRecognizerAuthorizationStatus.authorized`.
+    const SF_AUTH_STATUS_AUTHORIZED: i64 = 3;
+
+    /// `SFSpeechRecognitionTask` caps a single request at ~1 minute of audio.
+    /// Restart just under that so detection never silently stalls.
+    const TASK_RESTART_INTERVAL: Duration = Duration::from_secs(55);
+
+    /// How often the recognition loop wakes up to check whether detection
+    /// was disabled, so teardown happens promptly rather than only at the
+    /// end of `TASK_RESTART_INTERVAL`.
+    const DISABLE_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+    pub fn run(app: AppHandle) -> Result<(), Box<dyn std::error::Error>> {
+        if !request_authorization() {
+            let _ = app.emit(
+                "wake-word-permission-denied",
+                "Chief needs Speech Recognition access to listen for \"Hey Rosie\". Enable it in System Settings > Privacy & Security.",
+            );
+            return Ok(());
+        }
+
+        unsafe { autoreleasepool(|| run_recognition_loop(&app)) }
+    }
+
+    /// Calls `SFSpeechRecognizer.requestAuthorization` and blocks this
+    /// background thread until the user answers the system prompt (or we
+    /// give up after five minutes).
+    fn request_authorization() -> bool {
+        let (tx, rx) = channel();
+        unsafe {
+            let handler = ConcreteBlock::new(move |status: i64| {
+                let _ = tx.send(status == SF_AUTH_STATUS_AUTHORIZED);
+            });
+            let handler = handler.copy();
+            let _: () = msg_send![class!(SFSpeechRecognizer), requestAuthorization: &*handler];
+        }
+
+        rx.recv_timeout(Duration::from_secs(300)).unwrap_or(false)
+    }
+
+    unsafe fn run_recognition_loop(app: &AppHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let recognizer: id = msg_send![class!(SFSpeechRecognizer), new];
+        if recognizer == nil {
+            return Err("failed to create SFSpeechRecognizer".into());
+        }
+
+        let audio_engine: id = msg_send![class!(AVAudioEngine), new];
+
+        while crate::is_detection_enabled() {
+            let request: id = msg_send![class!(SFSpeechAudioBufferRecognitionRequest), new];
+            let _: () = msg_send![request, setRequiresOnDeviceRecognition: true];
+            let _: () = msg_send![request, setShouldReportPartialResults: true];
+
+            install_tap(audio_engine, request);
+            let _: BOOL = msg_send![audio_engine, startAndReturnError: nil];
+
+            let (done_tx, done_rx) = channel();
+            let app_for_result = app.clone();
+            let done_tx_for_error = done_tx.clone();
+            let done_tx_for_match = done_tx.clone();
+            // Latches on the first wake phrase match so partial results
+            // re-matching the same phrase later in the utterance (which
+            // `shouldReportPartialResults` keeps delivering) don't re-emit.
+            let matched = AtomicBool::new(false);
+            let result_handler = ConcreteBlock::new(move |result: id, error: id| {
+                if error != nil {
+                    let _ = done_tx_for_error.send(());
+                    return;
+                }
+                if result == nil {
+                    return;
+                }
+
+                if best_transcription_lowercased(result).contains(WAKE_PHRASE)
+                    && !matched.swap(true, Ordering::SeqCst)
+                {
+                    let _ = app_for_result.emit("wake-word-detected", ());
+                    // Reset the recognition request for the next utterance
+                    // instead of waiting for isFinal/the restart interval.
+                    let _ = done_tx_for_match.send(());
+                }
+
+                // `BOOL` is `i8` on x86_64 and `bool` on aarch64 - compare
+                // against `YES` explicitly rather than casting, since `i8 as
+                // bool` doesn't compile.
+                let is_final: BOOL = msg_send![result, isFinal];
+                if is_final == YES {
+                    let _ = done_tx.send(());
+                }
+            });
+            let result_handler = result_handler.copy();
+            let task: id = msg_send![
+                recognizer,
+                recognitionTaskWithRequest: request
+                resultHandler: &*result_handler
+            ];
+
+            // We stop waiting on this task/request when: it finishes on its
+            // own (commonly by hitting the OS's ~1 minute cap), a wake
+            // phrase match resets it, we hit our restart interval, or
+            // detection gets disabled. Poll in short slices rather than one
+            // long `recv_timeout` so disabling detection tears things down
+            // within `DISABLE_POLL_INTERVAL`, not up to
+            // `TASK_RESTART_INTERVAL`.
+            let restart_deadline = std::time::Instant::now() + TASK_RESTART_INTERVAL;
+            loop {
+                if done_rx.recv_timeout(DISABLE_POLL_INTERVAL).is_ok() {
+                    break;
+                }
+                if !crate::is_detection_enabled() || std::time::Instant::now() >= restart_deadline {
+                    break;
+                }
+            }
+
+            // Cancel the outgoing task before starting a fresh one on the
+            // next loop iteration - otherwise every reset/restart (match,
+            // isFinal, or the restart interval) leaks a still-live task.
+            let _: () = msg_send![task, cancel];
+
+            let input_node: id = msg_send![audio_engine, inputNode];
+            let _: () = msg_send![input_node, removeTapOnBus: 0u64];
+            let _: () = msg_send![audio_engine, stop];
+        }
+
+        Ok(())
+    }
+
+    unsafe fn install_tap(audio_engine: id, request: id) {
+        let input_node: id = msg_send![audio_engine, inputNode];
+        let format: id = msg_send![input_node, outputFormatForBus: 0u64];
+
+        let block = ConcreteBlock::new(move |buffer: id, _when: id| {
+            let _: () = msg_send![request, appendAudioPCMBuffer: buffer];
+        });
+        let block = block.copy();
+
+        let _: () = msg_send![
+            input_node,
+            installTapOnBus: 0u64
+            bufferSize: 1024u32
+            format: format
+            block: &*block
+        ];
+    }
+
+    /// Lowercases `result.bestTranscription.formattedString` for wake phrase
+    /// matching.
+    unsafe fn best_transcription_lowercased(result: id) -> String {
+        let best: id = msg_send![result, bestTranscription];
+        let formatted: id = msg_send![best, formattedString];
+        let utf8: *const std::os::raw::c_char = msg_send![formatted, UTF8String];
+        if utf8.is_null() {
+            return String::new();
+        }
+
+        std::ffi::CStr::from_ptr(utf8)
+            .to_string_lossy()
+            .to_lowercase()
+    }
+}